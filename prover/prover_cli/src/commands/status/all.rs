@@ -0,0 +1,69 @@
+use clap::Args as ClapArgs;
+
+use crate::errors::CLIErrors;
+
+use super::{batch, l1, OutputFormat};
+
+#[derive(ClapArgs)]
+pub struct Args {
+    /// Also check the status of this L1 batch, in addition to the L1 check.
+    #[arg(long)]
+    pub batch: Option<u32>,
+
+    /// Keep running the remaining checks after one fails instead of
+    /// aborting immediately; a summary of every failure is printed at the
+    /// end.
+    #[arg(long)]
+    pub no_fail_fast: bool,
+}
+
+/// Runs every status check in sequence, optionally continuing past
+/// failures so that one bad check doesn't hide the rest of the picture.
+pub(crate) async fn run(args: Args, format: OutputFormat) -> Result<(), CLIErrors> {
+    let mut errors = Vec::new();
+    let mut failed_checks = Vec::new();
+    let mut ran_batch = false;
+
+    if let Err(err) = l1::run(format).await {
+        errors.extend(err.0);
+        failed_checks.push("l1");
+        if !args.no_fail_fast {
+            return Err(CLIErrors(errors));
+        }
+    }
+
+    if let Some(batch_number) = args.batch {
+        ran_batch = true;
+        if let Err(err) = batch::run(
+            batch::Args {
+                batch: batch_number,
+                ..Default::default()
+            },
+            format,
+        )
+        .await
+        {
+            errors.extend(err.0);
+            failed_checks.push("batch");
+            if !args.no_fail_fast {
+                return Err(CLIErrors(errors));
+            }
+        }
+    }
+
+    if failed_checks.is_empty() {
+        if ran_batch {
+            println!("all checks passed");
+        } else {
+            println!("l1 checked, batch skipped (no --batch given)");
+        }
+        Ok(())
+    } else {
+        println!(
+            "{} check(s) failed: {}",
+            failed_checks.len(),
+            failed_checks.join(", ")
+        );
+        Err(CLIErrors(errors))
+    }
+}
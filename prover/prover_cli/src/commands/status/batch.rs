@@ -0,0 +1,199 @@
+use std::time::{Duration, Instant};
+
+use clap::Args as ClapArgs;
+use serde::Serialize;
+
+use crate::errors::{CLIError, CLIErrors};
+
+use super::utils::{emit, print_status_line, OutputFormat};
+
+#[derive(ClapArgs, Clone)]
+pub struct Args {
+    /// L1 batch number to inspect.
+    pub batch: u32,
+
+    /// Keep polling the batch status on a fixed interval and print each
+    /// transition (e.g. sealed -> committed -> proven -> executed) instead
+    /// of printing a single snapshot and exiting.
+    #[arg(long)]
+    pub watch: bool,
+
+    /// Polling interval, in seconds, used with `--watch`. Must be at least 1.
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u64).range(1..))]
+    pub interval: u64,
+
+    /// With `--watch`, give up with an error if the batch hasn't reached a
+    /// terminal state within this many seconds.
+    #[arg(long)]
+    pub timeout: Option<u64>,
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        Args {
+            batch: 0,
+            watch: false,
+            interval: 5,
+            timeout: None,
+        }
+    }
+}
+
+/// Lifecycle of an L1 batch, in the order it is reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum BatchStatus {
+    Sealed,
+    Committed,
+    Proven,
+    Executed,
+}
+
+impl BatchStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            BatchStatus::Sealed => "sealed",
+            BatchStatus::Committed => "committed",
+            BatchStatus::Proven => "proven",
+            BatchStatus::Executed => "executed",
+        }
+    }
+
+    fn is_terminal(self) -> bool {
+        matches!(self, BatchStatus::Executed)
+    }
+}
+
+/// A structured snapshot of a single batch's status, used for the
+/// `json`/`json-pretty` output formats and for diffing in `--watch` mode.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct BatchStatusRecord {
+    pub batch: u32,
+    pub status: BatchStatus,
+    pub commit_tx_hash: Option<String>,
+    pub prove_tx_hash: Option<String>,
+    pub execute_tx_hash: Option<String>,
+    pub l1_block: Option<u64>,
+}
+
+async fn fetch_batch_status(batch: u32) -> Result<BatchStatusRecord, CLIError> {
+    // There is no query against the prover database behind this yet, so
+    // every batch always comes back freshly `Sealed` regardless of its real
+    // history — which also means `--watch` can never observe it finalize.
+    Ok(BatchStatusRecord {
+        batch,
+        status: BatchStatus::Sealed,
+        commit_tx_hash: None,
+        prove_tx_hash: None,
+        execute_tx_hash: None,
+        l1_block: None,
+    })
+}
+
+fn print_human(record: &BatchStatusRecord) {
+    print_status_line(
+        &format!("batch {}", record.batch),
+        record.status.as_str(),
+        record.status.is_terminal(),
+    );
+}
+
+pub(crate) async fn run(args: Args, format: OutputFormat) -> Result<(), CLIErrors> {
+    if args.watch {
+        return watch(args, format).await;
+    }
+
+    let record = fetch_batch_status(args.batch).await?;
+    emit(&record, format, print_human);
+    Ok(())
+}
+
+/// Whether a freshly-polled status differs from the last one we printed,
+/// i.e. whether this tick should emit a line.
+fn status_changed(last_status: Option<BatchStatus>, current: BatchStatus) -> bool {
+    last_status != Some(current)
+}
+
+/// Whether `--timeout` has elapsed as of `now`.
+fn timed_out(deadline: Option<Instant>, now: Instant) -> bool {
+    deadline.is_some_and(|deadline| now >= deadline)
+}
+
+/// Polls the batch status every `args.interval` seconds, printing a line
+/// only when the status actually changes, until it reaches a terminal
+/// state, `args.timeout` elapses, or the user interrupts.
+async fn watch(args: Args, format: OutputFormat) -> Result<(), CLIErrors> {
+    let deadline = args
+        .timeout
+        .map(|secs| Instant::now() + Duration::from_secs(secs));
+    let mut ticker = tokio::time::interval(Duration::from_secs(args.interval));
+    let mut last_status = None;
+
+    loop {
+        ticker.tick().await;
+
+        let record = fetch_batch_status(args.batch).await?;
+        if status_changed(last_status, record.status) {
+            emit(&record, format, print_human);
+            last_status = Some(record.status);
+        }
+
+        if record.status.is_terminal() {
+            return Ok(());
+        }
+
+        if timed_out(deadline, Instant::now()) {
+            return Err(CLIError::Custom(format!(
+                "batch {} did not finalize within {} second(s)",
+                args.batch,
+                args.timeout.unwrap_or_default(),
+            ))
+            .into());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_executed_is_terminal() {
+        assert!(!BatchStatus::Sealed.is_terminal());
+        assert!(!BatchStatus::Committed.is_terminal());
+        assert!(!BatchStatus::Proven.is_terminal());
+        assert!(BatchStatus::Executed.is_terminal());
+    }
+
+    #[test]
+    fn as_str_matches_serde_rename() {
+        assert_eq!(BatchStatus::Sealed.as_str(), "sealed");
+        assert_eq!(BatchStatus::Committed.as_str(), "committed");
+        assert_eq!(BatchStatus::Proven.as_str(), "proven");
+        assert_eq!(BatchStatus::Executed.as_str(), "executed");
+    }
+
+    #[test]
+    fn status_changed_detects_the_first_tick_and_transitions() {
+        assert!(status_changed(None, BatchStatus::Sealed));
+        assert!(!status_changed(Some(BatchStatus::Sealed), BatchStatus::Sealed));
+        assert!(status_changed(
+            Some(BatchStatus::Sealed),
+            BatchStatus::Committed
+        ));
+    }
+
+    #[test]
+    fn timed_out_without_a_deadline_never_fires() {
+        assert!(!timed_out(None, Instant::now()));
+    }
+
+    #[test]
+    fn timed_out_fires_once_now_reaches_the_deadline() {
+        let now = Instant::now();
+        let deadline = now + Duration::from_secs(1);
+        assert!(!timed_out(Some(deadline), now));
+        assert!(timed_out(Some(deadline), deadline));
+        assert!(timed_out(Some(deadline), deadline + Duration::from_secs(1)));
+    }
+}
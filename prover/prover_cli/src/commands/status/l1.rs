@@ -0,0 +1,28 @@
+use serde::Serialize;
+
+use crate::errors::{CLIError, CLIErrors};
+
+use super::utils::{emit, print_status_line, OutputFormat};
+
+/// A structured snapshot of L1 status, used for the `json`/`json-pretty`
+/// output formats.
+#[derive(Debug, Serialize)]
+pub(crate) struct L1StatusRecord {
+    pub latest_l1_block: u64,
+}
+
+async fn fetch_latest_l1_block() -> Result<u64, CLIError> {
+    // No L1 RPC client is wired in yet, so this is a stand-in that always
+    // reports block 0 — it doesn't reflect the chain's actual head.
+    Ok(0)
+}
+
+pub(crate) async fn run(format: OutputFormat) -> Result<(), CLIErrors> {
+    let record = L1StatusRecord {
+        latest_l1_block: fetch_latest_l1_block().await?,
+    };
+    emit(&record, format, |record| {
+        print_status_line("latest L1 block", &record.latest_l1_block.to_string(), true);
+    });
+    Ok(())
+}
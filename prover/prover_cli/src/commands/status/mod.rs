@@ -1,22 +1,49 @@
-use clap::Subcommand;
+use clap::{Args as ClapArgs, Subcommand};
 
 use crate::errors::CLIErrors;
 
+mod all;
 pub(crate) mod batch;
 pub(crate) mod l1;
+mod repl;
 mod utils;
 
+pub(crate) use utils::OutputFormat;
+
+#[derive(ClapArgs)]
+pub struct StatusCommand {
+    /// Output format for the chosen status check.
+    #[arg(long, value_enum, global = true, default_value_t = OutputFormat::Human)]
+    pub format: OutputFormat,
+
+    #[command(subcommand)]
+    pub command: StatusSubcommand,
+}
+
 #[derive(Subcommand)]
-pub enum StatusCommand {
+pub enum StatusSubcommand {
     Batch(batch::Args),
     L1,
+    /// Run every status check in sequence, see `--no-fail-fast`.
+    All(all::Args),
+    /// Drop into an interactive session for repeatedly running `batch`/`l1`
+    /// checks without re-launching the binary.
+    Repl,
 }
 
 impl StatusCommand {
     pub(crate) async fn run(self) -> Result<(), CLIErrors> {
+        StatusSubcommand::run(self.command, self.format).await
+    }
+}
+
+impl StatusSubcommand {
+    pub(crate) async fn run(self, format: OutputFormat) -> Result<(), CLIErrors> {
         match self {
-            StatusCommand::Batch(args) => Ok(batch::run(args).await?),
-            StatusCommand::L1 => l1::run().await,
+            StatusSubcommand::Batch(args) => Ok(batch::run(args, format).await?),
+            StatusSubcommand::L1 => l1::run(format).await,
+            StatusSubcommand::All(args) => all::run(args, format).await,
+            StatusSubcommand::Repl => repl::run(format).await,
         }
     }
 }
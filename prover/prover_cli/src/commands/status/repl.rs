@@ -0,0 +1,288 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use clap::{CommandFactory, Parser};
+use rustyline::completion::{Completer, Pair};
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+
+use crate::errors::{CLIError, CLIErrors};
+
+use super::{OutputFormat, StatusSubcommand};
+
+/// The most recently typed `batch <n>` number, shared between the REPL
+/// loop and the completer so `batch <TAB>` can suggest it.
+type LastBatch = Rc<RefCell<Option<u32>>>;
+
+/// Wraps [`StatusSubcommand`] so a single REPL line can be parsed with the
+/// exact same `clap` definitions the binary uses for `status <subcommand>`.
+#[derive(Parser)]
+#[command(name = "status", no_binary_name = true)]
+struct ReplLine {
+    #[command(subcommand)]
+    command: StatusSubcommand,
+}
+
+/// How one command on a REPL line is chained to the next, mirroring shell
+/// semantics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Chain {
+    /// `;` — always run the next command.
+    Always,
+    /// `&&` — only run the next command if this one succeeded.
+    OnSuccess,
+    /// `||` — only run the next command if this one failed.
+    OnFailure,
+}
+
+/// Splits a line into `(chain leading into this segment, command text)`
+/// pairs, recognising `;`, `&&` and `||` as separators. The first segment
+/// has no leading chain. Separators inside a `'...'`/`"..."` quoted span are
+/// left untouched — `shlex` resolves the quoting when the segment is later
+/// parsed.
+fn split_chained(line: &str) -> Vec<(Option<Chain>, String)> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut pending_chain = None;
+    let mut chars = line.chars().peekable();
+    let mut quote = None;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' | '"' if quote.is_none() => {
+                quote = Some(c);
+                current.push(c);
+            }
+            q if quote == Some(q) => {
+                quote = None;
+                current.push(q);
+            }
+            ';' if quote.is_none() => {
+                parts.push((pending_chain.take(), std::mem::take(&mut current)));
+                pending_chain = Some(Chain::Always);
+            }
+            '&' if quote.is_none() && chars.peek() == Some(&'&') => {
+                chars.next();
+                parts.push((pending_chain.take(), std::mem::take(&mut current)));
+                pending_chain = Some(Chain::OnSuccess);
+            }
+            '|' if quote.is_none() && chars.peek() == Some(&'|') => {
+                chars.next();
+                parts.push((pending_chain.take(), std::mem::take(&mut current)));
+                pending_chain = Some(Chain::OnFailure);
+            }
+            _ => current.push(c),
+        }
+    }
+    parts.push((pending_chain.take(), current));
+    parts
+}
+
+/// Parses and runs a single sub-command, returning whether it succeeded.
+async fn dispatch(segment: &str, format: OutputFormat, last_batch: &LastBatch) -> bool {
+    let tokens = match shlex::split(segment) {
+        Some(tokens) => tokens,
+        None => {
+            eprintln!("error: unbalanced quotes in `{segment}`");
+            return false;
+        }
+    };
+
+    match ReplLine::try_parse_from(tokens) {
+        Ok(parsed) => {
+            if let StatusSubcommand::Batch(ref args) = parsed.command {
+                *last_batch.borrow_mut() = Some(args.batch);
+            }
+            match parsed.command.run(format).await {
+                Ok(()) => true,
+                Err(err) => {
+                    eprintln!("{err}");
+                    false
+                }
+            }
+        }
+        Err(err) => {
+            let _ = err.print();
+            false
+        }
+    }
+}
+
+/// Runs every `;`/`&&`/`||`-separated command on a REPL line, short-circuiting
+/// according to the chain operator that preceded each one.
+async fn run_line(line: &str, format: OutputFormat, last_batch: &LastBatch) {
+    let mut last_succeeded = true;
+    for (chain, segment) in split_chained(line) {
+        let segment = segment.trim();
+        if segment.is_empty() {
+            continue;
+        }
+
+        let should_run = match chain {
+            None | Some(Chain::Always) => true,
+            Some(Chain::OnSuccess) => last_succeeded,
+            Some(Chain::OnFailure) => !last_succeeded,
+        };
+        if should_run {
+            last_succeeded = dispatch(segment, format, last_batch).await;
+        }
+    }
+}
+
+/// Completes sub-command names at the start of a line, and the
+/// most-recently-used batch number after `batch`.
+struct StatusHelper {
+    last_batch: LastBatch,
+}
+
+impl Completer for StatusHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let start = line[..pos].rfind(' ').map(|i| i + 1).unwrap_or(0);
+        let word = &line[start..pos];
+
+        let candidates: Vec<String> = if start == 0 {
+            ReplLine::command()
+                .get_subcommands()
+                .map(|c| c.get_name().to_string())
+                .collect()
+        } else if line[..start].trim_end() == "batch" {
+            self.last_batch
+                .borrow()
+                .map(|batch| vec![batch.to_string()])
+                .unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+
+        let pairs = candidates
+            .into_iter()
+            .filter(|c| c.starts_with(word))
+            .map(|c| Pair {
+                display: c.clone(),
+                replacement: c,
+            })
+            .collect();
+        Ok((start, pairs))
+    }
+}
+
+impl Hinter for StatusHelper {
+    type Hint = String;
+}
+
+impl Highlighter for StatusHelper {}
+
+impl Validator for StatusHelper {}
+
+impl Helper for StatusHelper {}
+
+const HISTORY_FILE: &str = ".zksync_status_history";
+
+pub(crate) async fn run(format: OutputFormat) -> Result<(), CLIErrors> {
+    let last_batch: LastBatch = Rc::new(RefCell::new(None));
+
+    let mut editor: Editor<StatusHelper, DefaultHistory> =
+        Editor::new().map_err(|err| CLIError::Custom(err.to_string()))?;
+    editor.set_helper(Some(StatusHelper {
+        last_batch: Rc::clone(&last_batch),
+    }));
+
+    let history_path = dirs::home_dir().map(|home| home.join(HISTORY_FILE));
+    if let Some(path) = &history_path {
+        let _ = editor.load_history(path);
+    }
+
+    loop {
+        match editor.readline("status> ") {
+            Ok(line) => {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let _ = editor.add_history_entry(line.as_str());
+                run_line(&line, format, &last_batch).await;
+            }
+            Err(ReadlineError::Interrupted) | Err(ReadlineError::Eof) => break,
+            Err(err) => {
+                eprintln!("readline error: {err}");
+                break;
+            }
+        }
+    }
+
+    if let Some(path) = &history_path {
+        let _ = editor.save_history(path);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(parts: &[(Option<Chain>, String)]) -> Vec<&str> {
+        parts.iter().map(|(_, text)| text.as_str()).collect()
+    }
+
+    fn chains(parts: &[(Option<Chain>, String)]) -> Vec<Option<Chain>> {
+        parts.iter().map(|(chain, _)| *chain).collect()
+    }
+
+    #[test]
+    fn single_command_has_no_leading_chain() {
+        let parts = split_chained("batch 5");
+        assert_eq!(chains(&parts), vec![None]);
+        assert_eq!(texts(&parts), vec!["batch 5"]);
+    }
+
+    #[test]
+    fn semicolon_always_chains() {
+        let parts = split_chained("batch 5;l1");
+        assert_eq!(chains(&parts), vec![None, Some(Chain::Always)]);
+        assert_eq!(texts(&parts), vec!["batch 5", "l1"]);
+    }
+
+    #[test]
+    fn and_or_chain_on_success_and_failure() {
+        let parts = split_chained("batch 5 && l1 || batch 6");
+        assert_eq!(
+            chains(&parts),
+            vec![None, Some(Chain::OnSuccess), Some(Chain::OnFailure)]
+        );
+        assert_eq!(texts(&parts), vec!["batch 5 ", " l1 ", " batch 6"]);
+    }
+
+    #[test]
+    fn consecutive_separators_yield_an_empty_segment() {
+        let parts = split_chained("l1;;batch 5");
+        assert_eq!(
+            chains(&parts),
+            vec![None, Some(Chain::Always), Some(Chain::Always)]
+        );
+        assert_eq!(texts(&parts), vec!["l1", "", "batch 5"]);
+    }
+
+    #[test]
+    fn empty_line_yields_one_empty_segment() {
+        let parts = split_chained("");
+        assert_eq!(chains(&parts), vec![None]);
+        assert_eq!(texts(&parts), vec![""]);
+    }
+
+    #[test]
+    fn separators_inside_quotes_are_not_split() {
+        let parts = split_chained(r#"batch "5;6" && l1"#);
+        assert_eq!(chains(&parts), vec![None, Some(Chain::OnSuccess)]);
+        assert_eq!(texts(&parts), vec![r#"batch "5;6" "#, " l1"]);
+    }
+}
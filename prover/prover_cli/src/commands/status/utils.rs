@@ -0,0 +1,92 @@
+use clap::ValueEnum;
+use colored::Colorize;
+use serde::Serialize;
+
+/// Output format shared by every `status` sub-command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable, colourised text (default).
+    Human,
+    /// Compact JSON, one record per line.
+    Json,
+    /// Pretty-printed JSON.
+    JsonPretty,
+}
+
+/// Prints a single `<label>: <value>` line, colouring the value green when
+/// `success` is set and red otherwise. Used by the `human` format only.
+pub(crate) fn print_status_line(label: &str, value: &str, success: bool) {
+    let value = if success {
+        value.green()
+    } else {
+        value.red()
+    };
+    println!("{label}: {value}");
+}
+
+/// Emits `record` according to `format`: JSON (compact or pretty) for the
+/// machine-readable formats, or `human` for [`OutputFormat::Human`].
+pub(crate) fn emit<T: Serialize>(record: &T, format: OutputFormat, human: impl FnOnce(&T)) {
+    match format {
+        OutputFormat::Human => human(record),
+        OutputFormat::Json => match serde_json::to_string(record) {
+            Ok(json) => println!("{json}"),
+            Err(err) => eprintln!("failed to serialize status record: {err}"),
+        },
+        OutputFormat::JsonPretty => match serde_json::to_string_pretty(record) {
+            Ok(json) => println!("{json}"),
+            Err(err) => eprintln!("failed to serialize status record: {err}"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use serde::Serialize;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Sample {
+        a: u32,
+        b: &'static str,
+    }
+
+    #[test]
+    fn emit_human_calls_the_human_closure_only() {
+        let record = Sample { a: 1, b: "x" };
+        let called = Cell::new(false);
+        emit(&record, OutputFormat::Human, |_| called.set(true));
+        assert!(called.get());
+    }
+
+    #[test]
+    fn emit_json_skips_the_human_closure() {
+        let record = Sample { a: 1, b: "x" };
+        let called = Cell::new(false);
+        emit(&record, OutputFormat::Json, |_| called.set(true));
+        assert!(!called.get());
+    }
+
+    #[test]
+    fn emit_json_pretty_skips_the_human_closure() {
+        let record = Sample { a: 1, b: "x" };
+        let called = Cell::new(false);
+        emit(&record, OutputFormat::JsonPretty, |_| called.set(true));
+        assert!(!called.get());
+    }
+
+    #[test]
+    fn json_and_json_pretty_serialize_the_same_data() {
+        let record = Sample { a: 1, b: "x" };
+        let compact = serde_json::to_string(&record).unwrap();
+        let pretty = serde_json::to_string_pretty(&record).unwrap();
+        assert_eq!(compact, r#"{"a":1,"b":"x"}"#);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&pretty).unwrap(),
+            serde_json::from_str::<serde_json::Value>(&compact).unwrap()
+        );
+    }
+}
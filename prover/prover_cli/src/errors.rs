@@ -0,0 +1,42 @@
+use std::fmt;
+
+use thiserror::Error;
+
+/// A single failure surfaced by one of the `status` sub-commands.
+#[derive(Debug, Error)]
+pub enum CLIError {
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("L1 RPC error: {0}")]
+    L1Rpc(String),
+    #[error("{0}")]
+    Custom(String),
+}
+
+/// One or more [`CLIError`]s accumulated while running a status check.
+///
+/// Most commands only ever produce a single error, but `status all` keeps
+/// going after a failed sub-check and reports everything it collected at
+/// the end, hence the plural representation here.
+#[derive(Debug, Default)]
+pub struct CLIErrors(pub Vec<CLIError>);
+
+impl From<CLIError> for CLIErrors {
+    fn from(err: CLIError) -> Self {
+        CLIErrors(vec![err])
+    }
+}
+
+impl fmt::Display for CLIErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, err) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{err}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for CLIErrors {}